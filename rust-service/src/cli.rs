@@ -0,0 +1,25 @@
+use clap::{Parser, Subcommand};
+
+// No subcommand (or `serve`) keeps the previous behavior of standing up the
+// axum server and periodic scraper; the rest let operators exercise the
+// scraper and catalog offline without paying for the HTTP listener.
+#[derive(Debug, Parser)]
+#[command(name = "torque-rust-service", about = "Torque index scraper and API")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    Serve,
+    ScrapeOnce,
+    ScrapeStore {
+        id: String,
+    },
+    ListStores,
+    Export {
+        #[arg(long, default_value = "json")]
+        format: String,
+    },
+}
@@ -0,0 +1,139 @@
+// Structured vehicle-fitment extraction. Scans a normalized mod's title and
+// tags against a small, hard-coded set of known make/model/chassis/engine
+// combinations, producing zero or more FitmentRows that get persisted to the
+// `mod_fitment` table during normalization, so query_mods_from_db has
+// exact/range columns to join against instead of grepping search_text (an
+// "RB25" in a title shouldn't satisfy an "RB26" query).
+
+use crate::normalize_match_text;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitmentRow {
+    pub make: String,
+    pub model: String,
+    pub chassis_code: Option<String>,
+    pub engine_code: Option<String>,
+    pub year_from: Option<i32>,
+    pub year_to: Option<i32>,
+}
+
+// extract_fitment only emits a row when one of chassis_codes/engine_codes
+// appears as a whole token — matching on the code rather than the make/model
+// name keeps false positives low (a bare "M3" or "Civic" is far less
+// specific than "e46" or "k20a").
+struct FitmentPattern {
+    make: &'static str,
+    model: &'static str,
+    chassis_codes: &'static [&'static str],
+    engine_codes: &'static [&'static str],
+    year_from: i32,
+    year_to: i32,
+}
+
+const KNOWN_FITMENTS: &[FitmentPattern] = &[
+    FitmentPattern {
+        make: "Nissan",
+        model: "Skyline GT-R",
+        chassis_codes: &["bnr32", "bcnr33", "bnr34"],
+        engine_codes: &["rb26dett"],
+        year_from: 1989,
+        year_to: 2002,
+    },
+    FitmentPattern {
+        make: "Nissan",
+        model: "Silvia",
+        chassis_codes: &["s13", "s14", "s15"],
+        engine_codes: &["sr20det"],
+        year_from: 1988,
+        year_to: 2002,
+    },
+    FitmentPattern {
+        make: "Toyota",
+        model: "Supra",
+        chassis_codes: &["jza80"],
+        engine_codes: &["2jzgte", "2jzge"],
+        year_from: 1993,
+        year_to: 2002,
+    },
+    FitmentPattern {
+        make: "Toyota",
+        model: "GR86",
+        chassis_codes: &["zn8"],
+        engine_codes: &["fa24"],
+        year_from: 2022,
+        year_to: 2026,
+    },
+    FitmentPattern {
+        make: "Subaru",
+        model: "WRX STI",
+        chassis_codes: &["gdb", "grb", "vab"],
+        engine_codes: &["ej207", "ej257"],
+        year_from: 2001,
+        year_to: 2021,
+    },
+    FitmentPattern {
+        make: "Mazda",
+        model: "RX-7",
+        chassis_codes: &["fd3s"],
+        engine_codes: &["13bretu"],
+        year_from: 1992,
+        year_to: 2002,
+    },
+    FitmentPattern {
+        make: "BMW",
+        model: "M3",
+        chassis_codes: &["e46", "e36", "e92"],
+        engine_codes: &["s54", "s50", "s65"],
+        year_from: 1992,
+        year_to: 2013,
+    },
+    FitmentPattern {
+        make: "Honda",
+        model: "Civic Type R",
+        chassis_codes: &["ek9", "fd2", "fk8"],
+        engine_codes: &["b16b", "k20a", "k20c1"],
+        year_from: 1997,
+        year_to: 2023,
+    },
+];
+
+pub fn extract_fitment(title: &str, tags: &[String]) -> Vec<FitmentRow> {
+    let mut haystack = normalize_match_text(title);
+    for tag in tags {
+        haystack.push(' ');
+        haystack.push_str(&normalize_match_text(tag));
+    }
+    let tokens: Vec<&str> = haystack.split_whitespace().collect();
+
+    KNOWN_FITMENTS
+        .iter()
+        .filter_map(|pattern| {
+            let chassis_hit = pattern
+                .chassis_codes
+                .iter()
+                .find(|code| tokens.contains(code));
+            let engine_hit = pattern
+                .engine_codes
+                .iter()
+                .find(|code| tokens.contains(code));
+
+            if chassis_hit.is_none() && engine_hit.is_none() {
+                return None;
+            }
+
+            Some(FitmentRow {
+                make: pattern.make.to_string(),
+                // Normalized the same way `search_text` is, so `push_filters`
+                // can compare it against a normalized `model` query param
+                // with a plain `ILIKE` instead of re-deriving the same
+                // hyphen/case handling in SQL (stored "Skyline GT-R" would
+                // otherwise never match a normalized "gt r" query).
+                model: normalize_match_text(pattern.model),
+                chassis_code: chassis_hit.map(|code| code.to_string()),
+                engine_code: engine_hit.map(|code| code.to_string()),
+                year_from: Some(pattern.year_from),
+                year_to: Some(pattern.year_to),
+            })
+        })
+        .collect()
+}
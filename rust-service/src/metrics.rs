@@ -0,0 +1,109 @@
+// Prometheus metrics surfaced at `/internal/metrics`. Collectors live on a
+// single `Registry` owned by `AppState` so both the scraper and the axum
+// request middleware can record into the same exposition.
+
+use std::sync::Arc;
+
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
+    register_int_counter_with_registry, register_int_gauge_vec_with_registry, Encoder,
+    HistogramVec, IntCounter, IntCounterVec, IntGaugeVec, Registry, TextEncoder,
+};
+
+pub struct Metrics {
+    registry: Registry,
+    pub scrape_success_total: IntCounterVec,
+    pub scrape_failure_total: IntCounterVec,
+    pub mods_indexed: IntGaugeVec,
+    pub page_fetch_latency_seconds: HistogramVec,
+    pub rate_limit_backoffs_total: IntCounter,
+    pub http_requests_total: IntCounterVec,
+    pub http_request_duration_seconds: HistogramVec,
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Metrics").finish_non_exhaustive()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        let registry = Registry::new();
+
+        let scrape_success_total = register_int_counter_vec_with_registry!(
+            "scrape_success_total",
+            "Number of store scrapes that completed successfully",
+            &["store_id"],
+            registry
+        )
+        .expect("failed to register scrape_success_total");
+
+        let scrape_failure_total = register_int_counter_vec_with_registry!(
+            "scrape_failure_total",
+            "Number of store scrapes that failed",
+            &["store_id"],
+            registry
+        )
+        .expect("failed to register scrape_failure_total");
+
+        let mods_indexed = register_int_gauge_vec_with_registry!(
+            "mods_indexed",
+            "Number of mods most recently indexed for a store",
+            &["store_id"],
+            registry
+        )
+        .expect("failed to register mods_indexed");
+
+        let page_fetch_latency_seconds = register_histogram_vec_with_registry!(
+            "page_fetch_latency_seconds",
+            "Latency of a single products-page fetch against an upstream store",
+            &["store_id"],
+            registry
+        )
+        .expect("failed to register page_fetch_latency_seconds");
+
+        let rate_limit_backoffs_total = register_int_counter_with_registry!(
+            "rate_limit_backoffs_total",
+            "Number of HTTP 429 backoffs hit while scraping upstream stores",
+            registry
+        )
+        .expect("failed to register rate_limit_backoffs_total");
+
+        let http_requests_total = register_int_counter_vec_with_registry!(
+            "http_requests_total",
+            "Number of HTTP requests served by the API",
+            &["method", "path", "status"],
+            registry
+        )
+        .expect("failed to register http_requests_total");
+
+        let http_request_duration_seconds = register_histogram_vec_with_registry!(
+            "http_request_duration_seconds",
+            "Latency of HTTP requests served by the API",
+            &["method", "path"],
+            registry
+        )
+        .expect("failed to register http_request_duration_seconds");
+
+        Arc::new(Self {
+            registry,
+            scrape_success_total,
+            scrape_failure_total,
+            mods_indexed,
+            page_fetch_latency_seconds,
+            rate_limit_backoffs_total,
+            http_requests_total,
+            http_request_duration_seconds,
+        })
+    }
+
+    pub fn render(&self) -> String {
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buffer)
+            .expect("failed to encode metrics");
+        String::from_utf8(buffer).expect("metrics encoding produced invalid utf8")
+    }
+}
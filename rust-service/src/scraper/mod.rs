@@ -0,0 +1,41 @@
+// Pluggable store providers. Each storefront platform (Shopify, WooCommerce,
+// ...) implements StoreProvider and is selected at scrape time by
+// Store::kind, so fetch_store_mods stays platform-agnostic.
+
+mod retry;
+mod shopify;
+mod woocommerce;
+
+use async_trait::async_trait;
+use reqwest::Client;
+
+pub use retry::RetryPolicy;
+pub use shopify::ShopifyProvider;
+pub use woocommerce::WooCommerceProvider;
+
+use crate::metrics::Metrics;
+use crate::{AppError, NormalizedMod, ScrapeConfig, Store};
+
+#[async_trait]
+pub trait StoreProvider: Send + Sync {
+    // Providers that don't paginate are only ever called with page == 1.
+    fn supports_pagination(&self) -> bool;
+
+    async fn fetch_products(
+        &self,
+        client: &Client,
+        cfg: &ScrapeConfig,
+        store: &Store,
+        page: usize,
+        metrics: &Metrics,
+    ) -> Result<Vec<NormalizedMod>, AppError>;
+}
+
+// Unknown kinds fall back to Shopify, matching Store::kind's own default so
+// old STORES_JSON configs (written before `kind` existed) keep scraping as before.
+pub fn provider_for(store: &Store) -> Box<dyn StoreProvider> {
+    match store.kind.as_str() {
+        "woocommerce" => Box::new(WooCommerceProvider),
+        _ => Box::new(ShopifyProvider),
+    }
+}
@@ -0,0 +1,99 @@
+// A reusable backoff policy applied to every outbound scrape request, not
+// just Shopify's HTTP 429s: connection/timeout errors and 5xx statuses are
+// retried too, with full jitter so several rate-limited stores don't all
+// wake up and hammer their upstream at the same instant.
+
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use tokio::time::sleep;
+use tracing::warn;
+
+use crate::metrics::Metrics;
+use crate::AppError;
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: usize,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub factor: f64,
+}
+
+impl RetryPolicy {
+    // Full-jitter backoff, clamped to max_delay; an explicit Retry-After
+    // always takes precedence (also clamped, so a misbehaving upstream
+    // can't make a worker sleep for minutes).
+    fn delay_for_attempt(&self, attempt: usize, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let scaled_millis = self.base_delay.as_millis() as f64 * self.factor.powi(attempt as i32);
+        let capped_millis = scaled_millis.min(self.max_delay.as_millis() as f64).max(0.0);
+
+        let jittered_millis = if capped_millis <= 0.0 {
+            0.0
+        } else {
+            rand::thread_rng().gen_range(0.0..=capped_millis)
+        };
+
+        Duration::from_millis(jittered_millis as u64)
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|raw| raw.parse::<u64>().ok())
+        .map(|seconds| Duration::from_secs(seconds.clamp(1, 120)))
+}
+
+// Builds the request fresh on every attempt, since RequestBuilder isn't clonable.
+pub async fn send_with_retry(
+    build_request: impl Fn() -> RequestBuilder,
+    policy: &RetryPolicy,
+    metrics: &Metrics,
+    store_id: &str,
+) -> Result<Response, AppError> {
+    let mut attempt = 0_usize;
+
+    loop {
+        let fetch_started_at = std::time::Instant::now();
+        let outcome = build_request().send().await;
+        metrics
+            .page_fetch_latency_seconds
+            .with_label_values(&[store_id])
+            .observe(fetch_started_at.elapsed().as_secs_f64());
+
+        let (retryable, retry_after_hint) = match &outcome {
+            Ok(response) => (is_retryable_status(response.status()), retry_after(response)),
+            Err(error) => (error.is_timeout() || error.is_connect(), None),
+        };
+
+        if !retryable || attempt >= policy.max_retries {
+            return outcome.map_err(|error| AppError::Upstream(format!("{store_id} ({error})")));
+        }
+
+        if matches!(&outcome, Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS) {
+            metrics.rate_limit_backoffs_total.inc();
+        }
+
+        let delay = policy.delay_for_attempt(attempt, retry_after_hint);
+        warn!(
+            "retrying request to store '{}' (attempt {}), backing off for {:?}",
+            store_id,
+            attempt + 1,
+            delay
+        );
+        sleep(delay).await;
+        attempt += 1;
+    }
+}
@@ -0,0 +1,200 @@
+// The original (and still default) StoreProvider: Shopify's public
+// /products.json endpoint.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::retry::send_with_retry;
+use super::StoreProvider;
+use crate::metrics::Metrics;
+use crate::{AppError, NormalizedMod, ScrapeConfig, Store, Variant};
+
+#[derive(Debug, Deserialize)]
+struct ShopifyProductsResponse {
+    products: Vec<ShopifyProduct>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShopifyProduct {
+    id: i64,
+    title: String,
+    handle: String,
+    vendor: Option<String>,
+    #[serde(default)]
+    product_type: String,
+    #[serde(default)]
+    tags: ShopifyTags,
+    #[serde(default)]
+    images: Vec<ShopifyImage>,
+    #[serde(default)]
+    variants: Vec<ShopifyVariant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShopifyImage {
+    src: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ShopifyVariant {
+    id: i64,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    sku: Option<String>,
+    price: Option<String>,
+    #[serde(default = "default_available")]
+    available: bool,
+    #[serde(default)]
+    inventory_quantity: Option<i64>,
+}
+
+fn default_available() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ShopifyTags {
+    String(String),
+    Array(Vec<String>),
+}
+
+impl Default for ShopifyTags {
+    fn default() -> Self {
+        Self::Array(Vec::new())
+    }
+}
+
+pub struct ShopifyProvider;
+
+#[async_trait]
+impl StoreProvider for ShopifyProvider {
+    fn supports_pagination(&self) -> bool {
+        true
+    }
+
+    async fn fetch_products(
+        &self,
+        client: &Client,
+        cfg: &ScrapeConfig,
+        store: &Store,
+        page: usize,
+        metrics: &Metrics,
+    ) -> Result<Vec<NormalizedMod>, AppError> {
+        let payload = fetch_page_payload(client, cfg, store, page, metrics).await?;
+
+        Ok(payload
+            .products
+            .into_iter()
+            .map(|product| normalize_product(product, store))
+            .collect())
+    }
+}
+
+async fn fetch_page_payload(
+    client: &Client,
+    scrape_cfg: &ScrapeConfig,
+    store: &Store,
+    page: usize,
+    metrics: &Metrics,
+) -> Result<ShopifyProductsResponse, AppError> {
+    let url = format!(
+        "{}/products.json?limit={}&page={}",
+        store.base_url.trim_end_matches('/'),
+        scrape_cfg.page_limit,
+        page
+    );
+
+    let response = send_with_retry(
+        || client.get(&url),
+        &scrape_cfg.retry_policy,
+        metrics,
+        &store.id,
+    )
+    .await?;
+
+    let response = response.error_for_status().map_err(|error| {
+        AppError::Upstream(format!(
+            "{} (HTTP status client/server error ({}) for url ({}))",
+            store.id, error, url
+        ))
+    })?;
+
+    response
+        .json::<ShopifyProductsResponse>()
+        .await
+        .map_err(|error| AppError::Upstream(format!("{} ({})", store.id, error)))
+}
+
+fn normalize_product(product: ShopifyProduct, store: &Store) -> NormalizedMod {
+    let tags = normalize_tags(product.tags);
+    let images = product.images.into_iter().map(|image| image.src).collect();
+    let variants: Vec<Variant> = product
+        .variants
+        .iter()
+        .map(|variant| Variant {
+            id: variant.id.to_string(),
+            title: variant.title.clone(),
+            sku: variant.sku.clone(),
+            price: parse_variant_price(variant),
+            available: variant.available,
+            inventory_quantity: variant.inventory_quantity,
+        })
+        .collect();
+    let (price, price_max) = price_range(&variants);
+
+    NormalizedMod {
+        id: format!("{}:{}", store.id, product.id),
+        store_id: store.id.clone(),
+        title: product.title,
+        images,
+        price,
+        price_max,
+        vendor: product.vendor.unwrap_or_else(|| "Unknown".to_string()),
+        product_type: product.product_type,
+        tags,
+        product_url: format!(
+            "{}/products/{}",
+            store.base_url.trim_end_matches('/'),
+            product.handle
+        ),
+        price_changed_at: None,
+        variants,
+    }
+}
+
+fn normalize_tags(raw_tags: ShopifyTags) -> Vec<String> {
+    match raw_tags {
+        ShopifyTags::String(raw) => raw
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(ToString::to_string)
+            .collect(),
+        ShopifyTags::Array(values) => values
+            .into_iter()
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect(),
+    }
+}
+
+fn parse_variant_price(variant: &ShopifyVariant) -> f64 {
+    variant
+        .price
+        .as_ref()
+        .and_then(|value| value.parse::<f64>().ok())
+        .unwrap_or(0.0)
+}
+
+fn price_range(variants: &[Variant]) -> (f64, f64) {
+    let Some(first) = variants.first() else {
+        return (0.0, 0.0);
+    };
+
+    variants.iter().skip(1).fold((first.price, first.price), |(min, max), variant| {
+        (min.min(variant.price), max.max(variant.price))
+    })
+}
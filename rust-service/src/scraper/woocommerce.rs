@@ -0,0 +1,151 @@
+// A second StoreProvider, for stores running WooCommerce's Store API
+// (/wp-json/wc/store/products).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use super::retry::send_with_retry;
+use super::StoreProvider;
+use crate::metrics::Metrics;
+use crate::{AppError, NormalizedMod, ScrapeConfig, Store, Variant};
+
+#[derive(Debug, Deserialize)]
+struct WooProduct {
+    id: i64,
+    name: String,
+    permalink: String,
+    #[serde(default)]
+    sku: String,
+    #[serde(default = "default_is_in_stock")]
+    is_in_stock: bool,
+    #[serde(default)]
+    stock_quantity: Option<i64>,
+    #[serde(default)]
+    images: Vec<WooImage>,
+    prices: WooPrices,
+    #[serde(default)]
+    categories: Vec<WooCategory>,
+}
+
+fn default_is_in_stock() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct WooImage {
+    src: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WooCategory {
+    name: String,
+}
+
+// Prices come back as integer strings in the currency's minor unit (e.g.
+// cents), scaled by currency_minor_unit.
+#[derive(Debug, Deserialize)]
+struct WooPrices {
+    price: String,
+    #[serde(default = "default_currency_minor_unit")]
+    currency_minor_unit: u32,
+}
+
+fn default_currency_minor_unit() -> u32 {
+    2
+}
+
+pub struct WooCommerceProvider;
+
+#[async_trait]
+impl StoreProvider for WooCommerceProvider {
+    fn supports_pagination(&self) -> bool {
+        true
+    }
+
+    async fn fetch_products(
+        &self,
+        client: &Client,
+        cfg: &ScrapeConfig,
+        store: &Store,
+        page: usize,
+        metrics: &Metrics,
+    ) -> Result<Vec<NormalizedMod>, AppError> {
+        let url = format!(
+            "{}/wp-json/wc/store/products?per_page={}&page={}",
+            store.base_url.trim_end_matches('/'),
+            cfg.page_limit,
+            page
+        );
+
+        let response = send_with_retry(|| client.get(&url), &cfg.retry_policy, metrics, &store.id)
+            .await?
+            .error_for_status()
+            .map_err(|error| {
+                AppError::Upstream(format!(
+                    "{} (HTTP status client/server error ({}) for url ({}))",
+                    store.id, error, url
+                ))
+            })?;
+
+        let products = response
+            .json::<Vec<WooProduct>>()
+            .await
+            .map_err(|error| AppError::Upstream(format!("{} ({})", store.id, error)))?;
+
+        Ok(products
+            .into_iter()
+            .map(|product| normalize_product(product, store))
+            .collect())
+    }
+}
+
+fn normalize_product(product: WooProduct, store: &Store) -> NormalizedMod {
+    let images = product.images.into_iter().map(|image| image.src).collect();
+    let tags = product
+        .categories
+        .iter()
+        .map(|category| category.name.clone())
+        .collect();
+    let product_type = product
+        .categories
+        .first()
+        .map(|category| category.name.clone())
+        .unwrap_or_default();
+    let price = extract_price(&product.prices);
+
+    // The Store API only exposes stock/price at the product level for a
+    // simple product (no per-variation fetch here), so it's modeled as a
+    // single variant rather than the multi-SKU list Shopify gives us.
+    let variant = Variant {
+        id: product.id.to_string(),
+        title: product.name.clone(),
+        sku: (!product.sku.is_empty()).then_some(product.sku),
+        price,
+        available: product.is_in_stock,
+        inventory_quantity: product.stock_quantity,
+    };
+
+    NormalizedMod {
+        id: format!("{}:{}", store.id, product.id),
+        store_id: store.id.clone(),
+        title: product.name,
+        images,
+        price,
+        price_max: price,
+        vendor: store.name.clone(),
+        product_type,
+        tags,
+        product_url: product.permalink,
+        price_changed_at: None,
+        variants: vec![variant],
+    }
+}
+
+fn extract_price(prices: &WooPrices) -> f64 {
+    prices
+        .price
+        .parse::<f64>()
+        .map(|minor_units| minor_units / 10_f64.powi(prices.currency_minor_unit as i32))
+        .unwrap_or(0.0)
+}
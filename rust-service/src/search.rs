@@ -0,0 +1,278 @@
+// Structured criteria search: a small composable query language translated
+// into SQL via `sqlx::QueryBuilder`, as an alternative to the hard-coded
+// make/model/engine filters on `GET /internal/mods`.
+
+use serde::Deserialize;
+use serde_json::Value;
+use sqlx::{types::Json as SqlJson, Postgres, QueryBuilder};
+
+use crate::AppError;
+
+// Enum rather than a raw field name string, so an unrecognized field is
+// rejected at JSON-deserialization time instead of being interpolated into SQL.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Field {
+    Price,
+    Vendor,
+    ProductType,
+    Tags,
+    StoreId,
+    Title,
+}
+
+impl Field {
+    fn column(self) -> &'static str {
+        match self {
+            Field::Price => "price",
+            Field::Vendor => "vendor",
+            Field::ProductType => "product_type",
+            Field::Tags => "tags",
+            Field::StoreId => "store_id",
+            Field::Title => "title",
+        }
+    }
+
+    fn is_tags(self) -> bool {
+        matches!(self, Field::Tags)
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(self, Field::Price)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum FilterNode {
+    And { nodes: Vec<FilterNode> },
+    Or { nodes: Vec<FilterNode> },
+    Equals { field: Field, value: Value },
+    Range {
+        field: Field,
+        #[serde(default)]
+        gte: Option<Value>,
+        #[serde(default)]
+        lte: Option<Value>,
+    },
+    Contains { field: Field, value: String },
+    AnyOf { field: Field, values: Vec<Value> },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SortSpec {
+    pub field: Field,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+fn default_limit() -> i64 {
+    50
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Criteria {
+    #[serde(default)]
+    pub filters: Vec<FilterNode>,
+    #[serde(default)]
+    pub sort: Vec<SortSpec>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+pub fn push_where(qb: &mut QueryBuilder<Postgres>, filters: &[FilterNode]) -> Result<(), AppError> {
+    qb.push(" WHERE ");
+    push_group(qb, filters, " AND ")
+}
+
+fn push_group(qb: &mut QueryBuilder<Postgres>, nodes: &[FilterNode], joiner: &str) -> Result<(), AppError> {
+    if nodes.is_empty() {
+        qb.push("TRUE");
+        return Ok(());
+    }
+
+    qb.push("(");
+    for (idx, node) in nodes.iter().enumerate() {
+        if idx > 0 {
+            qb.push(joiner);
+        }
+        push_node(qb, node)?;
+    }
+    qb.push(")");
+
+    Ok(())
+}
+
+fn push_node(qb: &mut QueryBuilder<Postgres>, node: &FilterNode) -> Result<(), AppError> {
+    match node {
+        FilterNode::And { nodes } => push_group(qb, nodes, " AND "),
+        FilterNode::Or { nodes } => push_group(qb, nodes, " OR "),
+        FilterNode::Equals { field, value } => push_equals(qb, *field, value),
+        FilterNode::Range { field, gte, lte } => push_range(qb, *field, gte.as_ref(), lte.as_ref()),
+        FilterNode::Contains { field, value } => push_contains(qb, *field, value),
+        FilterNode::AnyOf { field, values } => push_any_of(qb, *field, values),
+    }
+}
+
+fn push_equals(qb: &mut QueryBuilder<Postgres>, field: Field, value: &Value) -> Result<(), AppError> {
+    if field.is_tags() {
+        let tag = value_as_text(value)?;
+        qb.push("tags @> ");
+        qb.push_bind(SqlJson(vec![tag]));
+        return Ok(());
+    }
+
+    qb.push(field.column());
+    qb.push(" = ");
+    push_scalar(qb, field, value)
+}
+
+fn push_range(
+    qb: &mut QueryBuilder<Postgres>,
+    field: Field,
+    gte: Option<&Value>,
+    lte: Option<&Value>,
+) -> Result<(), AppError> {
+    if field.is_tags() {
+        return Err(AppError::BadRequest(format!(
+            "'range' is not supported for field '{}'",
+            field.column()
+        )));
+    }
+
+    if gte.is_none() && lte.is_none() {
+        qb.push("TRUE");
+        return Ok(());
+    }
+
+    qb.push("(");
+    let mut first = true;
+
+    if let Some(value) = gte {
+        qb.push(field.column());
+        qb.push(" >= ");
+        push_scalar(qb, field, value)?;
+        first = false;
+    }
+
+    if let Some(value) = lte {
+        if !first {
+            qb.push(" AND ");
+        }
+        qb.push(field.column());
+        qb.push(" <= ");
+        push_scalar(qb, field, value)?;
+    }
+
+    qb.push(")");
+    Ok(())
+}
+
+fn push_contains(qb: &mut QueryBuilder<Postgres>, field: Field, value: &str) -> Result<(), AppError> {
+    if field.is_tags() {
+        qb.push("tags @> ");
+        qb.push_bind(SqlJson(vec![value.to_string()]));
+        return Ok(());
+    }
+
+    if field.is_numeric() {
+        return Err(AppError::BadRequest(format!(
+            "'contains' is not supported for field '{}'",
+            field.column()
+        )));
+    }
+
+    qb.push(field.column());
+    qb.push(" ILIKE ");
+    qb.push_bind(format!("%{value}%"));
+    Ok(())
+}
+
+fn push_any_of(qb: &mut QueryBuilder<Postgres>, field: Field, values: &[Value]) -> Result<(), AppError> {
+    if values.is_empty() {
+        qb.push("FALSE");
+        return Ok(());
+    }
+
+    if field.is_tags() {
+        let tags = values
+            .iter()
+            .map(value_as_text)
+            .collect::<Result<Vec<_>, _>>()?;
+        qb.push("tags ?| ");
+        qb.push_bind(tags);
+        return Ok(());
+    }
+
+    qb.push(field.column());
+    qb.push(" IN (");
+    for (idx, value) in values.iter().enumerate() {
+        if idx > 0 {
+            qb.push(", ");
+        }
+        push_scalar(qb, field, value)?;
+    }
+    qb.push(")");
+
+    Ok(())
+}
+
+fn push_scalar(qb: &mut QueryBuilder<Postgres>, field: Field, value: &Value) -> Result<(), AppError> {
+    match field {
+        Field::Price => {
+            let number = value_as_f64(value)?;
+            qb.push_bind(number);
+        }
+        _ => {
+            let text = value_as_text(value)?;
+            qb.push_bind(text);
+        }
+    }
+
+    Ok(())
+}
+
+fn value_as_f64(value: &Value) -> Result<f64, AppError> {
+    value
+        .as_f64()
+        .or_else(|| value.as_str().and_then(|raw| raw.parse::<f64>().ok()))
+        .ok_or_else(|| AppError::BadRequest(format!("expected a numeric value, got {value}")))
+}
+
+fn value_as_text(value: &Value) -> Result<String, AppError> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(number) => Ok(number.to_string()),
+        other => Err(AppError::BadRequest(format!("expected a string value, got {other}"))),
+    }
+}
+
+pub fn push_sort(qb: &mut QueryBuilder<Postgres>, sort: &[SortSpec]) {
+    qb.push(" ORDER BY ");
+
+    if sort.is_empty() {
+        qb.push("updated_at DESC");
+        return;
+    }
+
+    for (idx, spec) in sort.iter().enumerate() {
+        if idx > 0 {
+            qb.push(", ");
+        }
+        qb.push(spec.field.column());
+        qb.push(match spec.order {
+            SortOrder::Asc => " ASC",
+            SortOrder::Desc => " DESC",
+        });
+    }
+}